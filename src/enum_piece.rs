@@ -16,6 +16,24 @@ pub enum Color {
     Black,
 }
 
+impl Color {
+    /// Returns the other side: White for Black and Black for White.
+    pub const fn opposite(self) -> Color {
+        match self {
+            Color::White => Color::Black,
+            Color::Black => Color::White,
+        }
+    }
+
+    /// Returns the score sign for this side: +1 for White, -1 for Black.
+    pub const fn sign(&self) -> i32 {
+        match self {
+            Color::White => 1,
+            Color::Black => -1,
+        }
+    }
+}
+
 #[derive(Clone, Copy)]
 pub enum BoardSquares {
     A8,
@@ -83,3 +101,21 @@ pub enum BoardSquares {
     G1,
     H1,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Color;
+
+    #[test]
+    fn color_sign() {
+        assert_eq!(Color::White.sign(), 1);
+        assert_eq!(Color::Black.sign(), -1);
+    }
+
+    #[test]
+    fn color_opposite() {
+        assert!(matches!(Color::White.opposite(), Color::Black));
+        assert!(matches!(Color::Black.opposite(), Color::White));
+        assert!(matches!(Color::White.opposite().opposite(), Color::White));
+    }
+}